@@ -0,0 +1,21 @@
+//! Entry/exit/transition behaviors attached to a state machine model.
+//!
+//! This module is the home for the action side of a machine's behavior
+//! (what runs when a state is entered, exited, or a transition fires), as
+//! distinct from [`crate::core`]'s guard/condition vocabulary.
+
+use crate::core::Event;
+
+/// A side effect run on state entry, state exit, or transition firing.
+pub trait Action {
+    fn run(&self, event: &Event);
+}
+
+impl<F> Action for F
+where
+    F: Fn(&Event),
+{
+    fn run(&self, event: &Event) {
+        self(event)
+    }
+}
@@ -0,0 +1,91 @@
+//! Runtime-checked fallback builder, for machines assembled from data that
+//! isn't known until runtime (e.g. loaded from a config file), where the
+//! compile-time-enforced [`super::MachineBuilder`] path doesn't apply.
+
+use crate::model::{MachineModel, TransitionDef};
+
+/// A construction step was missing when [`DynamicBuilder::build`] was
+/// called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    MissingInitialState,
+    NoStatesDeclared,
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::MissingInitialState => write!(f, "no initial state was declared"),
+            BuildError::NoStatesDeclared => write!(f, "no states were declared"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builds a [`MachineModel`] without compile-time enforcement of required
+/// steps; [`DynamicBuilder::build`] checks them at runtime instead.
+#[derive(Debug, Clone, Default)]
+pub struct DynamicBuilder {
+    model: MachineModel,
+}
+
+impl DynamicBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn initial_state(mut self, state: impl Into<String>) -> Self {
+        let state = state.into();
+        if !self.model.states.contains(&state) {
+            self.model.states.push(state.clone());
+        }
+        self.model.initial_state = Some(state);
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        let state = state.into();
+        if !self.model.states.contains(&state) {
+            self.model.states.push(state);
+        }
+        self
+    }
+
+    pub fn transition(mut self, transition: TransitionDef) -> Self {
+        self.model.transitions.push(transition);
+        self
+    }
+
+    pub fn build(self) -> Result<MachineModel, BuildError> {
+        if self.model.initial_state.is_none() {
+            return Err(BuildError::MissingInitialState);
+        }
+        if self.model.states.is_empty() {
+            return Err(BuildError::NoStatesDeclared);
+        }
+        Ok(self.model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_fails_without_initial_state() {
+        let result = DynamicBuilder::new().state("idle").build();
+        assert_eq!(result, Err(BuildError::MissingInitialState));
+    }
+
+    #[test]
+    fn build_succeeds_with_initial_state() {
+        let model = DynamicBuilder::new()
+            .initial_state("idle")
+            .state("running")
+            .build()
+            .unwrap();
+        assert_eq!(model.initial_state, Some("idle".to_string()));
+        assert_eq!(model.states, vec!["idle".to_string(), "running".to_string()]);
+    }
+}
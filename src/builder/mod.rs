@@ -0,0 +1,142 @@
+//! Fluent construction of a [`MachineModel`].
+//!
+//! [`MachineBuilder`] uses the typed-builder pattern: phantom type
+//! parameters track which mandatory pieces have been supplied, so calling
+//! [`MachineBuilder::build`] before an initial state and at least one
+//! state have been declared is a compile error rather than a runtime
+//! panic. For machines whose shape isn't known until runtime, see
+//! [`dynamic::DynamicBuilder`], which checks the same requirements at
+//! [`dynamic::DynamicBuilder::build`] time instead.
+
+pub mod dynamic;
+
+pub use dynamic::{BuildError, DynamicBuilder};
+
+use std::marker::PhantomData;
+
+use crate::model::{MachineModel, TransitionDef};
+
+/// Marker: the mandatory piece has not been supplied yet.
+pub struct No;
+/// Marker: the mandatory piece has been supplied.
+pub struct Yes;
+
+/// Fluent builder for a [`MachineModel`]. `HasInitial` and `HasStates`
+/// track, at the type level, whether an initial state and at least one
+/// state have been declared ([`Self::initial_state`] sets both, since it
+/// also registers its state; [`Self::state`] sets `HasStates`);
+/// [`Self::build`] is only defined for `MachineBuilder<Yes, Yes>`.
+pub struct MachineBuilder<HasInitial, HasStates> {
+    model: MachineModel,
+    _has_initial: PhantomData<HasInitial>,
+    _has_states: PhantomData<HasStates>,
+}
+
+impl Default for MachineBuilder<No, No> {
+    fn default() -> Self {
+        MachineBuilder {
+            model: MachineModel::default(),
+            _has_initial: PhantomData,
+            _has_states: PhantomData,
+        }
+    }
+}
+
+impl MachineBuilder<No, No> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<HasStates> MachineBuilder<No, HasStates> {
+    /// Declares the machine's initial state. Also registers it as a known
+    /// state, so a single-state machine only needs this call.
+    pub fn initial_state(mut self, state: impl Into<String>) -> MachineBuilder<Yes, Yes> {
+        let state = state.into();
+        if !self.model.states.contains(&state) {
+            self.model.states.push(state.clone());
+        }
+        self.model.initial_state = Some(state);
+        MachineBuilder {
+            model: self.model,
+            _has_initial: PhantomData,
+            _has_states: PhantomData,
+        }
+    }
+}
+
+impl<HasInitial> MachineBuilder<HasInitial, No> {
+    pub fn state(mut self, state: impl Into<String>) -> MachineBuilder<HasInitial, Yes> {
+        let state = state.into();
+        if !self.model.states.contains(&state) {
+            self.model.states.push(state);
+        }
+        MachineBuilder {
+            model: self.model,
+            _has_initial: PhantomData,
+            _has_states: PhantomData,
+        }
+    }
+}
+
+impl<HasInitial> MachineBuilder<HasInitial, Yes> {
+    /// Declares an additional state once at least one is already known.
+    pub fn state(mut self, state: impl Into<String>) -> MachineBuilder<HasInitial, Yes> {
+        let state = state.into();
+        if !self.model.states.contains(&state) {
+            self.model.states.push(state);
+        }
+        self
+    }
+}
+
+impl<HasInitial, HasStates> MachineBuilder<HasInitial, HasStates> {
+    pub fn transition(mut self, transition: TransitionDef) -> Self {
+        self.model.transitions.push(transition);
+        self
+    }
+}
+
+impl MachineBuilder<Yes, Yes> {
+    pub fn build(self) -> MachineModel {
+        self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_state_alone_is_buildable() {
+        let model = MachineBuilder::new().initial_state("idle").build();
+        assert_eq!(model.initial_state, Some("idle".to_string()));
+        assert_eq!(model.states, vec!["idle".to_string()]);
+    }
+
+    #[test]
+    fn state_then_initial_state_is_buildable() {
+        let model = MachineBuilder::new()
+            .state("running")
+            .initial_state("idle")
+            .build();
+        assert_eq!(model.states, vec!["running".to_string(), "idle".to_string()]);
+    }
+
+    #[test]
+    fn additional_states_can_be_declared_after_the_first() {
+        let model = MachineBuilder::new()
+            .initial_state("idle")
+            .state("running")
+            .state("stopped")
+            .build();
+        assert_eq!(
+            model.states,
+            vec!["idle".to_string(), "running".to_string(), "stopped".to_string()]
+        );
+    }
+
+    // `MachineBuilder::new().build()` and
+    // `MachineBuilder::new().state("idle").build()` (no initial state) are
+    // both compile errors: `build` only exists on `MachineBuilder<Yes, Yes>`.
+}
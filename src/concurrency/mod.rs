@@ -0,0 +1,72 @@
+//! Thread scaffolding for driving a machine without blocking the
+//! transition engine on side effects — webhook delivery
+//! ([`crate::fsm_api::server`]) — and the wire codec for replication
+//! ([`codec`]).
+
+pub mod codec;
+
+pub use codec::{encode_ack, encode_event, encode_snapshot, Frame, FrameDecoder, Snapshot};
+
+use std::sync::mpsc;
+use std::thread;
+
+/// Runs submitted jobs on a dedicated background thread so callers (most
+/// notably the transition engine) never block waiting on them.
+pub struct Dispatcher {
+    sender: mpsc::Sender<Box<dyn FnOnce() + Send>>,
+}
+
+impl Dispatcher {
+    /// Spawns the background worker thread.
+    pub fn spawn() -> Self {
+        let (sender, receiver) = mpsc::channel::<Box<dyn FnOnce() + Send>>();
+        thread::spawn(move || {
+            for job in receiver {
+                job();
+            }
+        });
+        Dispatcher { sender }
+    }
+
+    /// Submits `job` for background execution; returns immediately
+    /// regardless of how long `job` takes to run.
+    pub fn submit(&self, job: impl FnOnce() + Send + 'static) {
+        // The worker thread only ever stops if it panics; a send failure
+        // means callbacks silently stop firing rather than the submitting
+        // side blocking or panicking.
+        let _ = self.sender.send(Box::new(job));
+    }
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Self::spawn()
+    }
+}
+
+impl Clone for Dispatcher {
+    fn clone(&self) -> Self {
+        Dispatcher {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    #[test]
+    fn submit_runs_job_without_blocking_caller() {
+        let dispatcher = Dispatcher::spawn();
+        let (done_tx, done_rx) = channel();
+        dispatcher.submit(move || {
+            let _ = done_tx.send(());
+        });
+        done_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("job should run on the background thread");
+    }
+}
@@ -0,0 +1,125 @@
+//! Coverage instrumentation for the transition engine.
+//!
+//! When enabled, a [`CoverageTracker`] records which states were entered,
+//! which transitions fired, and which guards were evaluated (and in which
+//! polarity) as a machine runs. [`crate::diagnostics::coverage`] turns the
+//! raw tracker into a [`crate::diagnostics::coverage::CoverageReport`]
+//! against a known universe of states/transitions/guards.
+
+use std::collections::HashSet;
+
+use crate::core::StateId;
+use crate::model::TransitionDef;
+
+/// Identifies a specific transition for coverage purposes. Keyed on more
+/// than just `(from, to)`: two distinct transitions between the same pair
+/// of states (different trigger event or guard) must be tracked
+/// independently, or firing one would silently mark the other "covered".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransitionKey {
+    pub from: StateId,
+    pub to: StateId,
+    pub event_kind: String,
+    pub guard_label: Option<String>,
+}
+
+impl From<&TransitionDef> for TransitionKey {
+    fn from(t: &TransitionDef) -> Self {
+        TransitionKey {
+            from: t.from.clone(),
+            to: t.to.clone(),
+            event_kind: t.event_kind.clone(),
+            guard_label: t.guard_label.clone(),
+        }
+    }
+}
+
+/// Accumulates the states, transitions, and guard polarities observed
+/// while a machine runs.
+#[derive(Debug, Default)]
+pub struct CoverageTracker {
+    visited_states: HashSet<StateId>,
+    fired_transitions: HashSet<TransitionKey>,
+    guard_true: HashSet<String>,
+    guard_false: HashSet<String>,
+}
+
+impl CoverageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_state_entered(&mut self, state: &str) {
+        self.visited_states.insert(state.to_string());
+    }
+
+    pub fn record_transition_fired(&mut self, transition: &TransitionDef) {
+        self.fired_transitions.insert(TransitionKey::from(transition));
+    }
+
+    pub fn record_guard_evaluated(&mut self, label: &str, result: bool) {
+        if result {
+            self.guard_true.insert(label.to_string());
+        } else {
+            self.guard_false.insert(label.to_string());
+        }
+    }
+
+    pub fn visited_states(&self) -> &HashSet<StateId> {
+        &self.visited_states
+    }
+
+    pub fn fired_transitions(&self) -> &HashSet<TransitionKey> {
+        &self.fired_transitions
+    }
+
+    pub fn guard_seen_true(&self, label: &str) -> bool {
+        self.guard_true.contains(label)
+    }
+
+    pub fn guard_seen_false(&self, label: &str) -> bool {
+        self.guard_false.contains(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from: &str, to: &str, event_kind: &str) -> TransitionDef {
+        TransitionDef {
+            from: from.to_string(),
+            to: to.to_string(),
+            event_kind: event_kind.to_string(),
+            guard_label: None,
+        }
+    }
+
+    #[test]
+    fn tracks_state_and_transition_visits() {
+        let mut tracker = CoverageTracker::new();
+        tracker.record_state_entered("idle");
+        tracker.record_transition_fired(&transition("idle", "running", "start"));
+        assert!(tracker.visited_states().contains("idle"));
+        assert!(tracker
+            .fired_transitions()
+            .contains(&TransitionKey::from(&transition("idle", "running", "start"))));
+    }
+
+    #[test]
+    fn distinguishes_transitions_sharing_the_same_state_pair() {
+        let mut tracker = CoverageTracker::new();
+        tracker.record_transition_fired(&transition("idle", "running", "start"));
+
+        let other = transition("idle", "running", "restart");
+        assert!(!tracker.fired_transitions().contains(&TransitionKey::from(&other)));
+    }
+
+    #[test]
+    fn tracks_guard_polarity_independently() {
+        let mut tracker = CoverageTracker::new();
+        tracker.record_guard_evaluated("threshold_exceeded", true);
+        assert!(tracker.guard_seen_true("threshold_exceeded"));
+        assert!(!tracker.guard_seen_false("threshold_exceeded"));
+    }
+}
@@ -0,0 +1,75 @@
+//! Core transition-engine types shared by the rest of the crate.
+//!
+//! This module owns the primitive vocabulary (events, values, guards) that
+//! [`crate::model`], [`crate::behavior`], and [`crate::fsm_api`] build on.
+
+pub mod coverage;
+pub mod vars;
+mod value;
+
+pub use value::Value;
+pub use vars::{replace_vars, UnresolvedVar};
+
+use std::collections::HashMap;
+
+/// Identifier for a state within a machine's configuration.
+pub type StateId = String;
+
+/// An event delivered to a running state machine.
+///
+/// `kind` names the event (compared against transition triggers and
+/// [`crate::fsm_api::filter::EventFilter::OfType`]); `payload` carries
+/// arbitrary attributes addressable by dotted path (e.g. `payload.temperature`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub kind: String,
+    pub payload: HashMap<String, Value>,
+}
+
+impl Event {
+    pub fn new(kind: impl Into<String>) -> Self {
+        Event {
+            kind: kind.into(),
+            payload: HashMap::new(),
+        }
+    }
+
+    pub fn with_payload(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.payload.insert(key.into(), value.into());
+        self
+    }
+
+    /// Resolves a dotted attribute path against this event.
+    ///
+    /// `event.kind` resolves to the event's kind as a [`Value::Str`];
+    /// `payload.<key>` looks up `<key>` (everything after the first dot) in
+    /// `payload`; any other path is looked up in `payload` verbatim (nested
+    /// payload maps are not currently supported).
+    pub fn get_attribute(&self, path: &str) -> Option<Value> {
+        let mut segments = path.splitn(2, '.');
+        let root = segments.next()?;
+        let rest = segments.next();
+        match (root, rest) {
+            ("event", Some("kind")) => Some(Value::Str(self.kind.clone())),
+            ("payload", Some(key)) => self.payload.get(key).cloned(),
+            _ => self.payload.get(path).cloned(),
+        }
+    }
+}
+
+/// Evaluates a boolean condition over an incoming event.
+///
+/// Transitions carry an optional guard; the engine only considers a
+/// transition a candidate if its guard (when present) evaluates to `true`.
+pub trait Guard {
+    fn evaluate(&self, event: &Event) -> bool;
+}
+
+impl<F> Guard for F
+where
+    F: Fn(&Event) -> bool,
+{
+    fn evaluate(&self, event: &Event) -> bool {
+        self(event)
+    }
+}
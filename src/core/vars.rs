@@ -0,0 +1,108 @@
+//! `${var}`-style context substitution for guard expressions, action
+//! parameters, and diagnostic labels, resolved against a [`Context`] at
+//! dispatch time. This lets the same machine definition be parameterized
+//! per-instance without recompiling the model.
+
+use crate::model::Context;
+
+/// A `${var}` placeholder referenced a key with no value in the `Context`
+/// and no `:-default` fallback was given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedVar {
+    pub key: String,
+}
+
+impl std::fmt::Display for UnresolvedVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unresolved context variable: {}", self.key)
+    }
+}
+
+impl std::error::Error for UnresolvedVar {}
+
+/// Scans `template` for `${key}` or `${key:-default}` tokens and
+/// substitutes each against `context`, returning the first unresolved key
+/// as an error if one has neither a value nor a fallback.
+pub fn replace_vars(template: &str, context: &Context) -> Result<String, UnresolvedVar> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after_open = &rest[start + 2..];
+        let Some(end) = after_open.find('}') else {
+            // Unterminated `${`: treat the rest of the template as literal.
+            out.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+        let token = &after_open[..end];
+        let (key, default) = match token.split_once(":-") {
+            Some((key, default)) => (key, Some(default)),
+            None => (token, None),
+        };
+
+        match context.get(key) {
+            Some(value) => out.push_str(&value.to_string()),
+            None => match default {
+                Some(default) => out.push_str(default),
+                None => {
+                    return Err(UnresolvedVar {
+                        key: key.to_string(),
+                    })
+                }
+            },
+        }
+
+        rest = &after_open[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Value;
+
+    #[test]
+    fn substitutes_known_variable() {
+        let mut ctx = Context::new();
+        ctx.set("threshold", Value::Int(42));
+        assert_eq!(
+            replace_vars("limit is ${threshold}", &ctx),
+            Ok("limit is 42".to_string())
+        );
+    }
+
+    #[test]
+    fn falls_back_to_default_when_unset() {
+        let ctx = Context::new();
+        assert_eq!(
+            replace_vars("limit is ${threshold:-100}", &ctx),
+            Ok("limit is 100".to_string())
+        );
+    }
+
+    #[test]
+    fn errors_on_unknown_key_without_default() {
+        let ctx = Context::new();
+        assert_eq!(
+            replace_vars("limit is ${threshold}", &ctx),
+            Err(UnresolvedVar {
+                key: "threshold".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn substitutes_multiple_tokens() {
+        let mut ctx = Context::new();
+        ctx.set("host", "localhost");
+        ctx.set("port", Value::Int(8080));
+        assert_eq!(
+            replace_vars("${host}:${port}", &ctx),
+            Ok("localhost:8080".to_string())
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Structured, append-only audit trail of state-machine decisions.
+//!
+//! Modeled on OPC UA's audit events: a decision is captured as a typed
+//! [`AuditRecord`] (not a formatted string), so consumers can serialize it
+//! to JSON or feed it into a SIEM rather than scraping log lines. This
+//! module does not run or observe a machine itself — this crate has no
+//! bundled dispatch loop — it's the sink an embedding application's own
+//! dispatch code reports to, one [`AuditRecord`] per decision, via
+//! [`AuditSink::record`].
+
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{Event, StateId};
+use crate::diagnostics::json::json_string;
+
+/// A transition that was considered for firing, along with the guard that
+/// gated it and whether that guard held.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateTransition {
+    pub to: StateId,
+    pub guard_label: Option<String>,
+    pub guard_result: bool,
+}
+
+/// The transition the engine actually fired, if any candidate's guard held.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiredTransition {
+    pub to: StateId,
+    pub exit_actions_run: Vec<String>,
+    pub entry_actions_run: Vec<String>,
+}
+
+/// One audited decision: the event that arrived, the configuration it was
+/// evaluated against, every candidate transition considered, and the
+/// outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditRecord {
+    pub timestamp_millis: u128,
+    pub source: StateId,
+    pub event: Event,
+    pub candidates: Vec<CandidateTransition>,
+    pub fired: Option<FiredTransition>,
+}
+
+impl AuditRecord {
+    pub fn now(source: StateId, event: Event) -> Self {
+        let timestamp_millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        AuditRecord {
+            timestamp_millis,
+            source,
+            event,
+            candidates: Vec::new(),
+            fired: None,
+        }
+    }
+
+    /// Renders the record as a JSON object. Written by hand rather than
+    /// pulling in a serialization crate, since the record shape is small
+    /// and fixed.
+    pub fn to_json(&self) -> String {
+        let candidates = self
+            .candidates
+            .iter()
+            .map(|c| {
+                format!(
+                    r#"{{"to":{},"guard_label":{},"guard_result":{}}}"#,
+                    json_string(&c.to),
+                    c.guard_label
+                        .as_deref()
+                        .map(json_string)
+                        .unwrap_or_else(|| "null".to_string()),
+                    c.guard_result
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let fired = match &self.fired {
+            None => "null".to_string(),
+            Some(f) => format!(
+                r#"{{"to":{},"exit_actions_run":[{}],"entry_actions_run":[{}]}}"#,
+                json_string(&f.to),
+                f.exit_actions_run
+                    .iter()
+                    .map(|s| json_string(s))
+                    .collect::<Vec<_>>()
+                    .join(","),
+                f.entry_actions_run
+                    .iter()
+                    .map(|s| json_string(s))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        };
+        format!(
+            r#"{{"timestamp_millis":{},"source":{},"event_kind":{},"candidates":[{}],"fired":{}}}"#,
+            self.timestamp_millis,
+            json_string(&self.source),
+            json_string(&self.event.kind),
+            candidates,
+            fired,
+        )
+    }
+}
+
+/// Destination for audit records as they are produced.
+pub trait AuditSink {
+    fn record(&self, record: AuditRecord);
+}
+
+/// Fixed-capacity in-memory sink; oldest records are evicted once full.
+pub struct RingBufferSink {
+    capacity: usize,
+    records: Mutex<VecDeque<AuditRecord>>,
+}
+
+impl RingBufferSink {
+    pub fn new(capacity: usize) -> Self {
+        RingBufferSink {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Runs `query` against the records currently buffered.
+    pub fn query(&self, query: &AuditQuery) -> Vec<AuditRecord> {
+        let records = self.records.lock().unwrap();
+        records.iter().filter(|r| query.matches(r)).cloned().collect()
+    }
+}
+
+impl AuditSink for RingBufferSink {
+    fn record(&self, record: AuditRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock().unwrap();
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+}
+
+/// Appends one JSON record per line to a file.
+pub struct FileSink {
+    path: Mutex<std::fs::File>,
+}
+
+impl FileSink {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(FileSink {
+            path: Mutex::new(file),
+        })
+    }
+}
+
+impl AuditSink for FileSink {
+    fn record(&self, record: AuditRecord) {
+        let mut file = self.path.lock().unwrap();
+        let _ = writeln!(file, "{}", record.to_json());
+    }
+}
+
+/// Filter applied when querying a sink that supports querying (see
+/// [`RingBufferSink::query`]).
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    pub since_millis: Option<u128>,
+    pub until_millis: Option<u128>,
+    pub state: Option<StateId>,
+    pub event_kind: Option<String>,
+}
+
+impl AuditQuery {
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        if let Some(since) = self.since_millis {
+            if record.timestamp_millis < since {
+                return false;
+            }
+        }
+        if let Some(until) = self.until_millis {
+            if record.timestamp_millis > until {
+                return false;
+            }
+        }
+        if let Some(state) = &self.state {
+            let touches_state = &record.source == state
+                || record.candidates.iter().any(|c| &c.to == state)
+                || record.fired.as_ref().is_some_and(|f| &f.to == state);
+            if !touches_state {
+                return false;
+            }
+        }
+        if let Some(kind) = &self.event_kind {
+            if &record.event.kind != kind {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_record(source: &str, event_kind: &str) -> AuditRecord {
+        let mut r = AuditRecord::now(source.to_string(), Event::new(event_kind));
+        r.candidates.push(CandidateTransition {
+            to: "target".to_string(),
+            guard_label: Some("always".to_string()),
+            guard_result: true,
+        });
+        r.fired = Some(FiredTransition {
+            to: "target".to_string(),
+            exit_actions_run: vec!["log_exit".to_string()],
+            entry_actions_run: vec!["log_entry".to_string()],
+        });
+        r
+    }
+
+    #[test]
+    fn ring_buffer_evicts_oldest_once_full() {
+        let sink = RingBufferSink::new(2);
+        sink.record(sample_record("a", "tick"));
+        sink.record(sample_record("b", "tick"));
+        sink.record(sample_record("c", "tick"));
+        let all = sink.query(&AuditQuery::default());
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].source, "b");
+        assert_eq!(all[1].source, "c");
+    }
+
+    #[test]
+    fn query_filters_by_state_and_event_kind() {
+        let sink = RingBufferSink::new(8);
+        sink.record(sample_record("idle", "start"));
+        sink.record(sample_record("running", "stop"));
+
+        let by_state = sink.query(&AuditQuery {
+            state: Some("idle".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(by_state.len(), 1);
+        assert_eq!(by_state[0].event.kind, "start");
+
+        let by_kind = sink.query(&AuditQuery {
+            event_kind: Some("stop".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(by_kind.len(), 1);
+        assert_eq!(by_kind[0].source, "running");
+    }
+
+    #[test]
+    fn to_json_escapes_quotes() {
+        let record = AuditRecord::now("s\"t".to_string(), Event::new("k"));
+        assert!(record.to_json().contains(r#"s\"t"#));
+    }
+}
@@ -0,0 +1,193 @@
+//! Coverage reporting built from a [`CoverageTracker`], analogous to what
+//! cargo-llvm-cov does for lines but at the statechart level.
+
+use crate::core::coverage::{CoverageTracker, TransitionKey};
+use crate::core::StateId;
+use crate::diagnostics::json::json_string;
+use crate::model::TransitionDef;
+
+/// A summarized view of which states, transitions, and guard polarities a
+/// run actually exercised, against the known universe of each.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    pub total_states: usize,
+    pub visited_states: usize,
+    pub never_entered_states: Vec<StateId>,
+
+    pub total_transitions: usize,
+    pub fired_transitions: usize,
+    pub never_fired_transitions: Vec<TransitionDef>,
+
+    /// Guards that were only ever observed resolving one way; `true` means
+    /// the guard was only ever seen passing, `false` means only failing.
+    pub single_polarity_guards: Vec<(String, bool)>,
+}
+
+impl CoverageReport {
+    pub fn generate(
+        tracker: &CoverageTracker,
+        known_states: &[StateId],
+        known_transitions: &[TransitionDef],
+        known_guards: &[String],
+    ) -> Self {
+        let never_entered_states: Vec<StateId> = known_states
+            .iter()
+            .filter(|s| !tracker.visited_states().contains(*s))
+            .cloned()
+            .collect();
+
+        let never_fired_transitions: Vec<TransitionDef> = known_transitions
+            .iter()
+            .filter(|t| !tracker.fired_transitions().contains(&TransitionKey::from(*t)))
+            .cloned()
+            .collect();
+
+        let single_polarity_guards: Vec<(String, bool)> = known_guards
+            .iter()
+            .filter_map(|g| {
+                match (tracker.guard_seen_true(g), tracker.guard_seen_false(g)) {
+                    (true, false) => Some((g.clone(), true)),
+                    (false, true) => Some((g.clone(), false)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        CoverageReport {
+            total_states: known_states.len(),
+            visited_states: known_states.len() - never_entered_states.len(),
+            never_entered_states,
+            total_transitions: known_transitions.len(),
+            fired_transitions: known_transitions.len() - never_fired_transitions.len(),
+            never_fired_transitions,
+            single_polarity_guards,
+        }
+    }
+
+    pub fn state_coverage_percent(&self) -> f64 {
+        percent(self.visited_states, self.total_states)
+    }
+
+    pub fn transition_coverage_percent(&self) -> f64 {
+        percent(self.fired_transitions, self.total_transitions)
+    }
+
+    /// Renders the report as JSON matching a simple, stable schema.
+    pub fn to_json(&self) -> String {
+        let never_entered = self
+            .never_entered_states
+            .iter()
+            .map(|s| json_string(s))
+            .collect::<Vec<_>>()
+            .join(",");
+        let never_fired = self
+            .never_fired_transitions
+            .iter()
+            .map(|t| {
+                format!(
+                    r#"{{"from":{},"to":{},"event_kind":{},"guard_label":{}}}"#,
+                    json_string(&t.from),
+                    json_string(&t.to),
+                    json_string(&t.event_kind),
+                    t.guard_label
+                        .as_deref()
+                        .map(json_string)
+                        .unwrap_or_else(|| "null".to_string()),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let single_polarity = self
+            .single_polarity_guards
+            .iter()
+            .map(|(label, only_true)| {
+                format!(r#"{{"label":{},"only_true":{}}}"#, json_string(label), only_true)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            r#"{{"total_states":{},"visited_states":{},"state_coverage_percent":{},"never_entered_states":[{}],"total_transitions":{},"fired_transitions":{},"transition_coverage_percent":{},"never_fired_transitions":[{}],"single_polarity_guards":[{}]}}"#,
+            self.total_states,
+            self.visited_states,
+            self.state_coverage_percent(),
+            never_entered,
+            self.total_transitions,
+            self.fired_transitions,
+            self.transition_coverage_percent(),
+            never_fired,
+            single_polarity,
+        )
+    }
+}
+
+fn percent(covered: usize, total: usize) -> f64 {
+    if total == 0 {
+        100.0
+    } else {
+        (covered as f64 / total as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from: &str, to: &str, event_kind: &str) -> TransitionDef {
+        TransitionDef {
+            from: from.to_string(),
+            to: to.to_string(),
+            event_kind: event_kind.to_string(),
+            guard_label: None,
+        }
+    }
+
+    #[test]
+    fn reports_never_entered_states_and_percent() {
+        let mut tracker = CoverageTracker::new();
+        tracker.record_state_entered("idle");
+
+        let report = CoverageReport::generate(
+            &tracker,
+            &["idle".to_string(), "running".to_string()],
+            &[],
+            &[],
+        );
+        assert_eq!(report.never_entered_states, vec!["running".to_string()]);
+        assert_eq!(report.state_coverage_percent(), 50.0);
+    }
+
+    #[test]
+    fn flags_guards_only_seen_in_one_polarity() {
+        let mut tracker = CoverageTracker::new();
+        tracker.record_guard_evaluated("threshold_exceeded", true);
+
+        let report =
+            CoverageReport::generate(&tracker, &[], &[], &["threshold_exceeded".to_string()]);
+        assert_eq!(
+            report.single_polarity_guards,
+            vec![("threshold_exceeded".to_string(), true)]
+        );
+    }
+
+    #[test]
+    fn empty_universe_reports_full_coverage() {
+        let tracker = CoverageTracker::new();
+        let report = CoverageReport::generate(&tracker, &[], &[], &[]);
+        assert_eq!(report.state_coverage_percent(), 100.0);
+    }
+
+    #[test]
+    fn distinct_transitions_between_the_same_states_are_tracked_separately() {
+        let mut tracker = CoverageTracker::new();
+        tracker.record_transition_fired(&transition("idle", "running", "start"));
+
+        let known = vec![
+            transition("idle", "running", "start"),
+            transition("idle", "running", "restart"),
+        ];
+        let report = CoverageReport::generate(&tracker, &[], &known, &[]);
+
+        assert_eq!(report.fired_transitions, 1);
+        assert_eq!(report.never_fired_transitions, vec![transition("idle", "running", "restart")]);
+    }
+}
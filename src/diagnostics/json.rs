@@ -0,0 +1,47 @@
+//! A single hand-rolled JSON string escaper shared by every diagnostics
+//! export (and by [`crate::fsm_api::server`]'s webhook/HTTP payloads),
+//! rather than each call site re-deriving its own — and risking missing a
+//! character a hand-rolled copy would have caught.
+
+/// Escapes `s` for embedding as a JSON string body (the caller still
+/// supplies the surrounding quotes).
+pub fn escape_json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Renders `s` as a complete, quoted JSON string literal.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    out.push_str(&escape_json_string(s));
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_quotes_and_backslashes() {
+        assert_eq!(json_string("s\"t\\u"), "\"s\\\"t\\\\u\"");
+    }
+
+    #[test]
+    fn escapes_control_characters() {
+        assert_eq!(json_string("a\nb\tc"), "\"a\\nb\\tc\"");
+        assert_eq!(json_string("\u{0001}"), "\"\\u0001\"");
+    }
+}
@@ -0,0 +1,10 @@
+//! Observability for a running state machine: audit trails, and (see
+//! [`crate::core`]'s coverage instrumentation) test-coverage reporting.
+
+pub mod audit;
+pub mod coverage;
+pub mod json;
+
+pub use audit::{AuditQuery, AuditRecord, AuditSink, CandidateTransition, FiredTransition};
+pub use coverage::CoverageReport;
+pub use json::{escape_json_string, json_string};
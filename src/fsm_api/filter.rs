@@ -0,0 +1,252 @@
+//! Content-based event filtering, modeled on OPC UA's select/where clauses.
+//!
+//! An [`EventFilter`] is a tree of operator nodes evaluated against an
+//! incoming [`Event`]. It gives callers a declarative way to decide which
+//! events reach the transition engine at all, without writing guard code on
+//! every transition. [`EventFilter::evaluate`] only reports whether an
+//! event passes; this module does not itself drop failing events or hand
+//! them to a [`DeadLetterSink`] — the caller's dispatch code does that.
+
+use std::cmp::Ordering;
+
+use crate::core::{Event, Guard, Value};
+
+/// A leaf value in a filter expression: either a literal or an attribute
+/// path resolved against the event being evaluated (see
+/// [`Event::get_attribute`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Literal(Value),
+    Path(String),
+}
+
+impl Operand {
+    fn resolve(&self, event: &Event) -> Option<Value> {
+        match self {
+            Operand::Literal(v) => Some(v.clone()),
+            Operand::Path(path) => event.get_attribute(path),
+        }
+    }
+}
+
+impl From<Value> for Operand {
+    fn from(v: Value) -> Self {
+        Operand::Literal(v)
+    }
+}
+
+/// A node in an event filter expression tree.
+///
+/// Call [`EventFilter::evaluate`] (or use an `EventFilter` as a
+/// [`Guard`]) to test an event against the tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventFilter {
+    Equals(Operand, Operand),
+    GreaterThan(Operand, Operand),
+    LessThan(Operand, Operand),
+    Between(Operand, Operand, Operand),
+    InList(Operand, Vec<Operand>),
+    Like(Operand, String),
+    OfType(String),
+    Not(Box<EventFilter>),
+    And(Vec<EventFilter>),
+    Or(Vec<EventFilter>),
+}
+
+impl EventFilter {
+    /// Walks the filter tree against `event`, returning whether it passes.
+    ///
+    /// An operand that fails to resolve (e.g. a payload path that is
+    /// absent) makes the enclosing comparison evaluate to `false` rather
+    /// than erroring, mirroring how a missing `WHERE` column drops a row.
+    pub fn evaluate(&self, event: &Event) -> bool {
+        match self {
+            EventFilter::Equals(a, b) => match (a.resolve(event), b.resolve(event)) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+            EventFilter::GreaterThan(a, b) => {
+                Self::compare(a, b, event, Ordering::is_gt)
+            }
+            EventFilter::LessThan(a, b) => Self::compare(a, b, event, Ordering::is_lt),
+            EventFilter::Between(value, lo, hi) => {
+                Self::compare(value, lo, event, Ordering::is_ge)
+                    && Self::compare(value, hi, event, Ordering::is_le)
+            }
+            EventFilter::InList(value, candidates) => match value.resolve(event) {
+                Some(value) => candidates
+                    .iter()
+                    .any(|candidate| candidate.resolve(event).as_ref() == Some(&value)),
+                None => false,
+            },
+            EventFilter::Like(value, glob) => match value.resolve(event) {
+                Some(Value::Str(s)) => glob_matches(glob, &s),
+                _ => false,
+            },
+            EventFilter::OfType(type_id) => event.kind == *type_id,
+            EventFilter::Not(inner) => !inner.evaluate(event),
+            EventFilter::And(nodes) => nodes.iter().all(|n| n.evaluate(event)),
+            EventFilter::Or(nodes) => nodes.iter().any(|n| n.evaluate(event)),
+        }
+    }
+
+    fn compare(
+        a: &Operand,
+        b: &Operand,
+        event: &Event,
+        accept: impl Fn(Ordering) -> bool,
+    ) -> bool {
+        match (a.resolve(event), b.resolve(event)) {
+            (Some(a), Some(b)) => a
+                .partial_compare(&b)
+                .map(&accept)
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
+impl Guard for EventFilter {
+    fn evaluate(&self, event: &Event) -> bool {
+        EventFilter::evaluate(self, event)
+    }
+}
+
+/// Destination for events that an [`EventFilter`] rejects, so they are
+/// observable instead of silently vanishing.
+pub trait DeadLetterSink {
+    fn reject(&self, event: &Event, filter: &EventFilter);
+}
+
+/// Translates a simple glob (`*` and `?` wildcards) into a match against
+/// `text`, matching OPC UA's `Like` operator semantics.
+///
+/// `EventFilter::Like` gates untrusted incoming events, so this must not
+/// be naive recursive backtracking over `*` — that's exponential in the
+/// number of stars and lets a single crafted event/pattern pair hang the
+/// evaluating thread. Instead this is the standard two-pointer wildcard
+/// matcher: `star_idx`/`star_match_idx` remember the most recent `*`
+/// and how much of `text` it has already absorbed, so a mismatch after a
+/// `*` advances by one character and retries rather than re-exploring
+/// every split point; it takes at most about `glob.len() * text.len()`
+/// steps.
+fn glob_matches(glob: &str, text: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut gi, mut ti) = (0, 0);
+    let mut star_idx: Option<usize> = None;
+    let mut star_match_idx = 0;
+
+    while ti < text.len() {
+        if gi < glob.len() && glob[gi] == '*' {
+            star_idx = Some(gi);
+            star_match_idx = ti;
+            gi += 1;
+        } else if gi < glob.len() && (glob[gi] == '?' || glob[gi] == text[ti]) {
+            gi += 1;
+            ti += 1;
+        } else if let Some(si) = star_idx {
+            gi = si + 1;
+            star_match_idx += 1;
+            ti = star_match_idx;
+        } else {
+            return false;
+        }
+    }
+
+    while gi < glob.len() && glob[gi] == '*' {
+        gi += 1;
+    }
+    gi == glob.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: &str) -> Event {
+        Event::new(kind)
+    }
+
+    #[test]
+    fn equals_compares_literal_and_path() {
+        let filter = EventFilter::Equals(
+            Operand::Path("event.kind".into()),
+            Operand::Literal(Value::Str("tick".into())),
+        );
+        assert!(filter.evaluate(&event("tick")));
+        assert!(!filter.evaluate(&event("tock")));
+    }
+
+    #[test]
+    fn between_is_inclusive() {
+        let e = Event::new("sample").with_payload("temperature", 50.0);
+        let filter = EventFilter::Between(
+            Operand::Path("payload.temperature".into()),
+            Operand::Literal(Value::Float(0.0)),
+            Operand::Literal(Value::Float(50.0)),
+        );
+        assert!(filter.evaluate(&e));
+    }
+
+    #[test]
+    fn in_list_matches_any_candidate() {
+        let e = event("alert");
+        let filter = EventFilter::InList(
+            Operand::Path("event.kind".into()),
+            vec![
+                Operand::Literal(Value::Str("alert".into())),
+                Operand::Literal(Value::Str("warning".into())),
+            ],
+        );
+        assert!(filter.evaluate(&e));
+    }
+
+    #[test]
+    fn like_supports_glob_wildcards() {
+        let e = event("sensor.temperature.high");
+        let filter = EventFilter::Like(Operand::Path("event.kind".into()), "sensor.*.high".into());
+        assert!(filter.evaluate(&e));
+    }
+
+    #[test]
+    fn like_rejects_pathological_glob_without_exponential_blowup() {
+        // `Like` gates untrusted events, so a crafted pattern/event pair
+        // must not be able to hang the evaluating thread; this pattern is
+        // exponential under naive recursive backtracking over `*` but
+        // resolves immediately under the two-pointer matcher.
+        let pattern = "a*a*a*a*a*a*a*a*a*a*a*a*a*a*a*a*a*a*a*a*b";
+        let non_matching = "a".repeat(35);
+        assert!(!glob_matches(pattern, &non_matching));
+
+        let matching = format!("{non_matching}b");
+        assert!(glob_matches(pattern, &matching));
+    }
+
+    #[test]
+    fn missing_attribute_fails_closed() {
+        let e = event("tick");
+        let filter = EventFilter::Equals(
+            Operand::Path("payload.missing".into()),
+            Operand::Literal(Value::Int(1)),
+        );
+        assert!(!filter.evaluate(&e));
+    }
+
+    #[test]
+    fn not_and_or_compose() {
+        let e = event("tick");
+        let filter = EventFilter::And(vec![
+            EventFilter::OfType("tick".into()),
+            EventFilter::Not(Box::new(EventFilter::OfType("tock".into()))),
+        ]);
+        assert!(filter.evaluate(&e));
+
+        let filter = EventFilter::Or(vec![
+            EventFilter::OfType("tock".into()),
+            EventFilter::OfType("tick".into()),
+        ]);
+        assert!(filter.evaluate(&e));
+    }
+}
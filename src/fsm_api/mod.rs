@@ -0,0 +1,9 @@
+//! Public-facing API surface for embedding and driving a `gotstate` machine.
+
+pub mod filter;
+#[cfg(feature = "server")]
+pub mod server;
+
+pub use filter::EventFilter;
+#[cfg(feature = "server")]
+pub use server::Server;
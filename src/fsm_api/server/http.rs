@@ -0,0 +1,188 @@
+//! Minimal dependency-free HTTP control endpoint: `POST /events` injects
+//! an event, `GET /configuration` reports the active state.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::concurrency::Dispatcher;
+use crate::core::{Event, StateId};
+use crate::diagnostics::json_string;
+
+use super::webhook::{fire_webhooks, TransitionPayload, WebhookRegistration};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerError(pub String);
+
+impl std::fmt::Display for ServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "server error: {}", self.0)
+    }
+}
+
+impl std::error::Error for ServerError {}
+
+struct ServerState {
+    current_configuration: StateId,
+    injected_events: VecDeque<Event>,
+    webhooks: Vec<WebhookRegistration>,
+}
+
+/// A running HTTP control endpoint for a machine. Events POSTed to it are
+/// queued for the embedding application to drain with
+/// [`Server::take_injected_events`] and feed to the transition engine;
+/// transitions the engine fires are reported back via
+/// [`Server::notify_transition`], which both updates the reported
+/// configuration and dispatches any matching webhooks.
+pub struct Server {
+    _listener_thread: JoinHandle<()>,
+    state: Arc<Mutex<ServerState>>,
+}
+
+impl Server {
+    pub fn bind(addr: &str, initial_state: StateId) -> Result<Self, ServerError> {
+        let listener = TcpListener::bind(addr).map_err(|e| ServerError(e.to_string()))?;
+        let state = Arc::new(Mutex::new(ServerState {
+            current_configuration: initial_state,
+            injected_events: VecDeque::new(),
+            webhooks: Vec::new(),
+        }));
+        let state_for_thread = Arc::clone(&state);
+        let listener_thread = thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle_connection(stream, &state_for_thread);
+            }
+        });
+        Ok(Server {
+            _listener_thread: listener_thread,
+            state,
+        })
+    }
+
+    pub fn register_webhook(&self, registration: WebhookRegistration) {
+        self.state.lock().unwrap().webhooks.push(registration);
+    }
+
+    /// Drains events queued by `POST /events` since the last call.
+    pub fn take_injected_events(&self) -> Vec<Event> {
+        let mut state = self.state.lock().unwrap();
+        state.injected_events.drain(..).collect()
+    }
+
+    /// Reports a transition the engine fired: updates the configuration
+    /// `GET /configuration` reports and fires any matching webhooks
+    /// through `dispatcher` without blocking the caller.
+    pub fn notify_transition(
+        &self,
+        dispatcher: &Dispatcher,
+        from: StateId,
+        to: StateId,
+        event_kind: String,
+    ) {
+        let webhooks = {
+            let mut state = self.state.lock().unwrap();
+            state.current_configuration = to.clone();
+            state.webhooks.clone()
+        };
+        fire_webhooks(&webhooks, dispatcher, TransitionPayload::now(from, to, event_kind));
+    }
+}
+
+fn handle_connection(stream: TcpStream, state: &Arc<Mutex<ServerState>>) {
+    let mut reader = BufReader::new(stream.try_clone().expect("clone tcp stream"));
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+        return;
+    }
+
+    let mut content_length = 0usize;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).unwrap_or(0) == 0 || header == "\r\n" {
+            break;
+        }
+        if let Some(value) = header
+            .to_ascii_lowercase()
+            .strip_prefix("content-length:")
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+
+    let mut body = vec![0u8; content_length];
+    if content_length > 0 {
+        use std::io::Read;
+        let _ = reader.read_exact(&mut body);
+    }
+    let body = String::from_utf8_lossy(&body);
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+
+    let mut stream = stream;
+    match (method, path) {
+        ("GET", "/configuration") => {
+            let configuration = state.lock().unwrap().current_configuration.clone();
+            respond_json(
+                &mut stream,
+                200,
+                &format!(r#"{{"configuration":{}}}"#, json_string(&configuration)),
+            );
+        }
+        ("POST", "/events") => match extract_string_field(&body, "kind") {
+            Some(kind) => {
+                state.lock().unwrap().injected_events.push_back(Event::new(kind));
+                respond_json(&mut stream, 202, r#"{"status":"accepted"}"#);
+            }
+            None => respond_json(&mut stream, 400, r#"{"error":"missing \"kind\" field"}"#),
+        },
+        _ => respond_json(&mut stream, 404, r#"{"error":"not found"}"#),
+    }
+}
+
+/// Pulls `"field":"value"` out of a flat JSON object by substring search.
+/// Sufficient for the single-field request bodies this endpoint accepts;
+/// not a general JSON parser.
+fn extract_string_field(body: &str, field: &str) -> Option<String> {
+    let needle = format!("\"{field}\"");
+    let after_key = &body[body.find(&needle)? + needle.len()..];
+    let after_colon = &after_key[after_key.find(':')? + 1..];
+    let start = after_colon.find('"')? + 1;
+    let end = after_colon[start..].find('"')? + start;
+    Some(after_colon[start..end].to_string())
+}
+
+fn respond_json(stream: &mut TcpStream, status: u16, body: &str) {
+    let reason = match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n{body}",
+        len = body.len(),
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_flat_string_field() {
+        assert_eq!(
+            extract_string_field(r#"{"kind":"tick","other":1}"#, "kind"),
+            Some("tick".to_string())
+        );
+    }
+
+    #[test]
+    fn missing_field_returns_none() {
+        assert_eq!(extract_string_field(r#"{"other":1}"#, "kind"), None);
+    }
+}
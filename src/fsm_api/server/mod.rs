@@ -0,0 +1,14 @@
+//! Remote control surface for a running machine, mirroring tokio-modbus's
+//! optional `server` module: feature-gated, and off by default.
+//!
+//! Exposes a machine over HTTP — `POST /events` injects an event, `GET
+//! /configuration` reads the active state — and lets callers register
+//! webhooks that fire on transitions or state entry. Webhook delivery is
+//! dispatched through [`crate::concurrency::Dispatcher`] so a slow or
+//! unreachable endpoint never stalls the transition engine.
+
+mod http;
+mod webhook;
+
+pub use http::{Server, ServerError};
+pub use webhook::{TransitionPayload, WebhookRegistration, WebhookTrigger};
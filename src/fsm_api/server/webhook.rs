@@ -0,0 +1,182 @@
+//! Webhook registration and delivery.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::concurrency::Dispatcher;
+use crate::core::StateId;
+use crate::diagnostics::json_string;
+
+/// The condition under which a registered webhook fires.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookTrigger {
+    /// Fires when this exact transition is taken.
+    Transition { from: StateId, to: StateId },
+    /// Fires whenever the named state is entered, regardless of origin.
+    StateEntry { state: StateId },
+    /// Fires on every transition.
+    Any,
+}
+
+/// A callback URL and the condition that triggers a POST to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookRegistration {
+    pub url: String,
+    pub trigger: WebhookTrigger,
+}
+
+impl WebhookRegistration {
+    fn matches(&self, from: &str, to: &str) -> bool {
+        match &self.trigger {
+            WebhookTrigger::Any => true,
+            WebhookTrigger::StateEntry { state } => state == to,
+            WebhookTrigger::Transition { from: f, to: t } => f == from && t == to,
+        }
+    }
+}
+
+/// The JSON body posted to a matching webhook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionPayload {
+    pub from: StateId,
+    pub to: StateId,
+    pub event_kind: String,
+    pub timestamp_millis: u128,
+}
+
+impl TransitionPayload {
+    pub fn now(from: StateId, to: StateId, event_kind: String) -> Self {
+        TransitionPayload {
+            from,
+            to,
+            event_kind,
+            timestamp_millis: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"from":{},"to":{},"event_kind":{},"timestamp_millis":{}}}"#,
+            json_string(&self.from),
+            json_string(&self.to),
+            json_string(&self.event_kind),
+            self.timestamp_millis
+        )
+    }
+}
+
+/// Dispatches `payload` to every registration whose trigger matches,
+/// one [`Dispatcher::submit`] job per webhook, so the caller never waits
+/// on delivery. The dispatcher runs jobs one at a time on its single
+/// worker thread, so a slow endpoint delays the jobs queued after it.
+pub fn fire_webhooks(
+    registrations: &[WebhookRegistration],
+    dispatcher: &Dispatcher,
+    payload: TransitionPayload,
+) {
+    for registration in registrations {
+        if !registration.matches(&payload.from, &payload.to) {
+            continue;
+        }
+        let url = registration.url.clone();
+        let body = payload.to_json();
+        dispatcher.submit(move || {
+            let _ = post_json(&url, &body);
+        });
+    }
+}
+
+/// Minimal dependency-free HTTP/1.1 POST, sufficient for best-effort
+/// webhook delivery without pulling in an HTTP client crate.
+fn post_json(url: &str, body: &str) -> std::io::Result<()> {
+    let (host, path) = split_url(url);
+    let mut stream = TcpStream::connect(host)?;
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: {host}\r\nContent-Type: application/json\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n{body}",
+        path = path,
+        host = host,
+        len = body.len(),
+        body = body,
+    );
+    stream.write_all(request.as_bytes())?;
+    let mut discard = [0u8; 256];
+    while stream.read(&mut discard)? > 0 {}
+    Ok(())
+}
+
+/// Splits a `host:port/path` webhook URL, after stripping any leading
+/// `http://` or `https://`, into its host/port and path components.
+fn split_url(url: &str) -> (&str, &str) {
+    let without_scheme = url
+        .strip_prefix("http://")
+        .or_else(|| url.strip_prefix("https://"))
+        .unwrap_or(url);
+    match without_scheme.find('/') {
+        Some(idx) => (&without_scheme[..idx], &without_scheme[idx..]),
+        None => (without_scheme, "/"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_trigger_matches_every_transition() {
+        let reg = WebhookRegistration {
+            url: "localhost:0".into(),
+            trigger: WebhookTrigger::Any,
+        };
+        assert!(reg.matches("idle", "running"));
+    }
+
+    #[test]
+    fn transition_trigger_requires_exact_match() {
+        let reg = WebhookRegistration {
+            url: "localhost:0".into(),
+            trigger: WebhookTrigger::Transition {
+                from: "idle".into(),
+                to: "running".into(),
+            },
+        };
+        assert!(reg.matches("idle", "running"));
+        assert!(!reg.matches("running", "idle"));
+    }
+
+    #[test]
+    fn state_entry_trigger_ignores_origin() {
+        let reg = WebhookRegistration {
+            url: "localhost:0".into(),
+            trigger: WebhookTrigger::StateEntry {
+                state: "running".into(),
+            },
+        };
+        assert!(reg.matches("idle", "running"));
+        assert!(reg.matches("paused", "running"));
+        assert!(!reg.matches("idle", "paused"));
+    }
+
+    #[test]
+    fn split_url_separates_host_and_path() {
+        assert_eq!(split_url("http://localhost:8080/hooks"), ("localhost:8080", "/hooks"));
+        assert_eq!(split_url("localhost:8080"), ("localhost:8080", "/"));
+    }
+
+    #[test]
+    fn payload_json_escapes_attacker_controlled_event_kind() {
+        let payload = TransitionPayload {
+            from: "idle".into(),
+            to: "running".into(),
+            event_kind: r#"tick","injected":true"#.into(),
+            timestamp_millis: 0,
+        };
+        assert_eq!(
+            payload.to_json(),
+            r#"{"from":"idle","to":"running","event_kind":"tick\",\"injected\":true","timestamp_millis":0}"#
+        );
+    }
+}
@@ -1,5 +1,19 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+//! Composable building blocks for a state machine: a static [`model`]
+//! definition, guard/filter evaluation, context-variable substitution,
+//! audit/coverage instrumentation, sandboxed action execution, and a
+//! remote-control server with a replication wire codec.
+//!
+//! This crate does not itself ship a dispatch loop that selects a
+//! transition, evaluates its guard, and runs entry/exit actions — each
+//! module here is a standalone piece an embedding application's own
+//! dispatch code drives and reports to (e.g. calling
+//! [`diagnostics::audit::AuditSink::record`] or
+//! [`core::coverage::CoverageTracker::record_transition_fired`] per
+//! decision). Doc comments that refer to "the transition engine" describe
+//! that caller-owned loop, not a component defined in this crate.
+
 #![allow(dead_code)]
 #![allow(clippy::cognitive_complexity)]
 #![allow(clippy::type_complexity)]
@@ -12,4 +26,3 @@ pub mod diagnostics;
 pub mod fsm_api;
 pub mod model;
 pub mod resource;
-pub mod validator;
@@ -0,0 +1,28 @@
+//! Mutable per-instance key-value state, so a single machine definition
+//! can be parameterized (thresholds, resource names, …) without
+//! recompiling the model. See [`crate::core::vars::replace_vars`] for how
+//! guards and action labels reference context values.
+
+use std::collections::HashMap;
+
+use crate::core::Value;
+
+/// A mutable map of named values, resolved against at dispatch time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Context {
+    values: HashMap<String, Value>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+}
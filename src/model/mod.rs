@@ -0,0 +1,38 @@
+//! The static definition of a state machine: its states and transitions,
+//! as assembled by [`crate::builder`] and consumed by the embedding
+//! application's transition engine (built on [`crate::core`]'s types).
+
+pub mod context;
+
+pub use context::Context;
+
+use crate::core::StateId;
+
+/// A single transition between two states, triggered by an event kind and
+/// optionally gated by a named guard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransitionDef {
+    pub from: StateId,
+    pub to: StateId,
+    pub event_kind: String,
+    pub guard_label: Option<String>,
+}
+
+/// The complete static shape of a machine: its declared states, its
+/// initial state, and the transitions between them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MachineModel {
+    pub states: Vec<StateId>,
+    pub initial_state: Option<StateId>,
+    pub transitions: Vec<TransitionDef>,
+}
+
+impl MachineModel {
+    pub fn known_guards(&self) -> Vec<String> {
+        self.transitions
+            .iter()
+            .filter_map(|t| t.guard_label.clone())
+            .collect()
+    }
+
+}
@@ -0,0 +1,163 @@
+//! Wall-clock timeout and cooperative cancellation for a single action
+//! run.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::core::StateId;
+
+/// A cooperative cancellation flag shared between the caller and a running
+/// action. Rust threads can't be forcibly killed, so [`run_with_budget`]
+/// passes the token to `action` itself, which must poll
+/// [`CancellationToken::is_cancelled`] at its own safe points to actually
+/// stop doing work; `run_with_budget` only guarantees the *caller* stops
+/// waiting once the budget is exceeded. An action that never checks the
+/// token keeps running, and consuming its thread, indefinitely after
+/// `TimedOut` is returned.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// The enforced limits a single entry/exit/transition action runs under.
+#[derive(Clone)]
+pub struct ActionBudget {
+    pub timeout: Duration,
+    pub cancellation: Option<CancellationToken>,
+    /// State the engine should fall back to if the action times out or
+    /// its filesystem policy (see [`crate::resource::sandbox`]) cannot be
+    /// applied, instead of leaving the machine hung mid-transition.
+    pub fallback_state: Option<StateId>,
+}
+
+impl ActionBudget {
+    pub fn new(timeout: Duration) -> Self {
+        ActionBudget {
+            timeout,
+            cancellation: None,
+            fallback_state: None,
+        }
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub fn with_fallback_state(mut self, state: impl Into<StateId>) -> Self {
+        self.fallback_state = Some(state.into());
+        self
+    }
+}
+
+/// What happened when an action ran under a budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    Completed,
+    TimedOut,
+    Cancelled,
+    /// The action never ran because its sandbox policy (see
+    /// [`crate::resource::sandbox`]) could not be applied. Filesystem
+    /// accesses the policy denies while the action runs fail inside the
+    /// action and are not reported as an outcome.
+    SandboxRejected(String),
+}
+
+/// Runs `action` on a dedicated thread and waits up to `budget.timeout`
+/// for it to finish. On timeout the caller gets `ActionOutcome::TimedOut`
+/// back immediately and should drive the machine to
+/// `budget.fallback_state`, emitting a diagnostics event, rather than
+/// hang; `action` is handed the same [`CancellationToken`] `budget` was
+/// built with (or a fresh, never-cancelled one if it wasn't) so it can
+/// poll for cancellation at its own safe points, but the thread itself is
+/// abandoned rather than killed, since Rust has no mechanism to forcibly
+/// stop one — an `action` that never checks the token keeps running, and
+/// holding its thread, forever after this function returns.
+pub fn run_with_budget(
+    budget: &ActionBudget,
+    action: impl FnOnce(&CancellationToken) + Send + 'static,
+) -> ActionOutcome {
+    let token = budget.cancellation.clone().unwrap_or_default();
+    if token.is_cancelled() {
+        return ActionOutcome::Cancelled;
+    }
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let action_token = token.clone();
+    thread::spawn(move || {
+        action(&action_token);
+        let _ = done_tx.send(());
+    });
+
+    match done_rx.recv_timeout(budget.timeout) {
+        Ok(()) => ActionOutcome::Completed,
+        Err(mpsc::RecvTimeoutError::Timeout) => ActionOutcome::TimedOut,
+        Err(mpsc::RecvTimeoutError::Disconnected) => ActionOutcome::TimedOut,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completed_action_reports_completed() {
+        let budget = ActionBudget::new(Duration::from_secs(1));
+        let outcome = run_with_budget(&budget, |_token| {});
+        assert_eq!(outcome, ActionOutcome::Completed);
+    }
+
+    #[test]
+    fn slow_action_times_out() {
+        let budget = ActionBudget::new(Duration::from_millis(10));
+        let outcome = run_with_budget(&budget, |_token| thread::sleep(Duration::from_millis(200)));
+        assert_eq!(outcome, ActionOutcome::TimedOut);
+    }
+
+    #[test]
+    fn pre_cancelled_token_short_circuits() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let budget = ActionBudget::new(Duration::from_secs(1)).with_cancellation(token);
+        let outcome = run_with_budget(&budget, |_token| {});
+        assert_eq!(outcome, ActionOutcome::Cancelled);
+    }
+
+    #[test]
+    fn action_receives_the_same_token_cancelled_after_it_starts() {
+        let token = CancellationToken::new();
+        let budget = ActionBudget::new(Duration::from_secs(5)).with_cancellation(token.clone());
+
+        let (started_tx, started_rx) = mpsc::channel();
+        let (saw_cancel_tx, saw_cancel_rx) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            run_with_budget(&budget, move |action_token| {
+                started_tx.send(()).unwrap();
+                while !action_token.is_cancelled() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                saw_cancel_tx.send(()).unwrap();
+            })
+        });
+
+        started_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        token.cancel();
+        saw_cancel_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(handle.join().unwrap(), ActionOutcome::Completed);
+    }
+}
@@ -0,0 +1,66 @@
+//! Bounded execution environment for entry/exit/transition actions: a
+//! per-action wall-clock timeout, cooperative cancellation, and (Linux,
+//! behind the `sandbox` feature) filesystem confinement. Lets a machine
+//! embed untrusted or heavy side-effecting actions in a long-lived
+//! service without one hanging or misbehaving action taking the whole
+//! machine down with it.
+
+pub mod budget;
+#[cfg(all(target_os = "linux", feature = "sandbox"))]
+pub mod sandbox;
+
+pub use budget::{ActionBudget, ActionOutcome, CancellationToken};
+
+#[cfg(not(all(target_os = "linux", feature = "sandbox")))]
+use budget::run_with_budget;
+
+#[cfg(all(target_os = "linux", feature = "sandbox"))]
+use sandbox::FilesystemPolicy;
+
+/// Runs `action` under `budget`, first confining its filesystem access to
+/// `filesystem_policy` (Linux + `sandbox` feature only). A policy the
+/// kernel rejects is reported immediately as
+/// [`ActionOutcome::SandboxRejected`] without waiting out the timeout;
+/// the action never runs in that case. The caller should drive the
+/// machine to `budget.fallback_state` on anything other than `Completed`.
+/// As with [`budget::run_with_budget`], a timed-out `action` is not
+/// killed, only abandoned — if it never polls the [`CancellationToken`]
+/// it was handed, it keeps running, and keeps doing confined filesystem
+/// I/O, on its own thread indefinitely.
+#[cfg(all(target_os = "linux", feature = "sandbox"))]
+pub fn run_sandboxed(
+    budget: &ActionBudget,
+    filesystem_policy: FilesystemPolicy,
+    action: impl FnOnce(&CancellationToken) + Send + 'static,
+) -> ActionOutcome {
+    use std::sync::mpsc;
+    use std::thread;
+
+    let token = budget.cancellation.clone().unwrap_or_default();
+    if token.is_cancelled() {
+        return ActionOutcome::Cancelled;
+    }
+
+    let (done_tx, done_rx) = mpsc::channel();
+    let action_token = token.clone();
+    thread::spawn(move || {
+        if let Err(e) = sandbox::enforce_for_current_thread(&filesystem_policy) {
+            let _ = done_tx.send(ActionOutcome::SandboxRejected(e.to_string()));
+            return;
+        }
+        action(&action_token);
+        let _ = done_tx.send(ActionOutcome::Completed);
+    });
+
+    done_rx
+        .recv_timeout(budget.timeout)
+        .unwrap_or(ActionOutcome::TimedOut)
+}
+
+#[cfg(not(all(target_os = "linux", feature = "sandbox")))]
+pub fn run_sandboxed(
+    budget: &ActionBudget,
+    action: impl FnOnce(&CancellationToken) + Send + 'static,
+) -> ActionOutcome {
+    run_with_budget(budget, action)
+}
@@ -0,0 +1,136 @@
+//! Linux filesystem confinement for actions, in the spirit of
+//! Landlock/birdcage: a declarative allow-list of paths an action may
+//! touch, enforced by the kernel rather than by convention.
+//!
+//! Behind the `sandbox` feature (and only compiled on Linux) so embedding
+//! a machine on other platforms, or without the policy, carries no cost.
+
+use std::path::PathBuf;
+
+/// What an action is allowed to do to a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// A single allowed path and the access it's granted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathRule {
+    pub path: PathBuf,
+    pub access: Access,
+}
+
+/// The filesystem confinement policy an action runs under. An empty
+/// policy (no rules) denies all filesystem access once applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilesystemPolicy {
+    pub rules: Vec<PathRule>,
+}
+
+impl FilesystemPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allow(mut self, path: impl Into<PathBuf>, access: Access) -> Self {
+        self.rules.push(PathRule {
+            path: path.into(),
+            access,
+        });
+        self
+    }
+}
+
+/// The policy was rejected by the kernel, or this process lacks Landlock
+/// support entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SandboxError(pub String);
+
+impl std::fmt::Display for SandboxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sandbox error: {}", self.0)
+    }
+}
+
+impl std::error::Error for SandboxError {}
+
+/// Applies `policy` to the *current thread* via Landlock, restricting all
+/// filesystem access for the remainder of the thread's life to the rules
+/// it declares. Called at the top of the dedicated thread
+/// [`crate::resource::run_sandboxed`] spawns for an action,
+/// before running the action's body, so the restriction never leaks to
+/// the engine's own thread.
+///
+/// Landlock rulesets are additive and cannot be relaxed once applied;
+/// there is deliberately no corresponding "undo" here.
+pub fn enforce_for_current_thread(policy: &FilesystemPolicy) -> Result<(), SandboxError> {
+    landlock_backend::apply(policy)
+}
+
+#[cfg(target_os = "linux")]
+mod landlock_backend {
+    use super::{Access, FilesystemPolicy, SandboxError};
+    use landlock::{
+        Access as LandlockAccess, AccessFs, PathFdError, Ruleset, RulesetAttr, RulesetCreatedAttr,
+        RulesetError, ABI,
+    };
+
+    pub fn apply(policy: &FilesystemPolicy) -> Result<(), SandboxError> {
+        let abi = ABI::V3;
+        let mut ruleset = Ruleset::default()
+            .handle_access(AccessFs::from_all(abi))
+            .map_err(ruleset_error)?
+            .create()
+            .map_err(ruleset_error)?;
+
+        for rule in &policy.rules {
+            let access = match rule.access {
+                Access::ReadOnly => AccessFs::from_read(abi),
+                Access::ReadWrite => AccessFs::from_all(abi),
+            };
+            ruleset = ruleset
+                .add_rule(landlock::PathBeneath::new(
+                    landlock::PathFd::new(&rule.path).map_err(path_fd_error)?,
+                    access,
+                ))
+                .map_err(ruleset_error)?;
+        }
+
+        ruleset.restrict_self().map_err(ruleset_error)?;
+        Ok(())
+    }
+
+    fn ruleset_error(e: RulesetError) -> SandboxError {
+        SandboxError(e.to_string())
+    }
+
+    fn path_fd_error(e: PathFdError) -> SandboxError {
+        SandboxError(e.to_string())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod landlock_backend {
+    use super::{FilesystemPolicy, SandboxError};
+
+    pub fn apply(_policy: &FilesystemPolicy) -> Result<(), SandboxError> {
+        Err(SandboxError(
+            "filesystem sandboxing is only supported on Linux (Landlock)".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_builder_accumulates_rules() {
+        let policy = FilesystemPolicy::new()
+            .allow("/tmp/work", Access::ReadWrite)
+            .allow("/etc/resolv.conf", Access::ReadOnly);
+        assert_eq!(policy.rules.len(), 2);
+        assert_eq!(policy.rules[0].access, Access::ReadWrite);
+    }
+}